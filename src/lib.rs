@@ -8,10 +8,15 @@
 //! let x: Option<i32> = Some(async {5}).transpose().await;
 //! # }
 //! ```
-use std::{
+#![no_std]
+use core::{
     future::{Future, IntoFuture},
     pin::Pin,
 };
+#[cfg(feature = "futures-core")]
+use futures_core::future::FusedFuture;
+#[cfg(feature = "futures-util")]
+use futures_util::future::Either;
 
 pub trait TransposeFuture {
     type Output: Future;
@@ -28,24 +33,43 @@ impl<F: IntoFuture> TransposeFuture for Option<F> {
     /// # }
     /// ```
     fn transpose(self) -> Self::Output {
-        TransposedOption(self.map(IntoFuture::into_future))
+        TransposedOption {
+            inner: self.map(IntoFuture::into_future),
+            done: false,
+        }
     }
 }
-pub struct TransposedOption<F>(Option<F>);
+pub struct TransposedOption<F> {
+    inner: Option<F>,
+    done: bool,
+}
 impl<F: Future> Future for TransposedOption<F> {
     type Output = Option<F::Output>;
     fn poll(
-        self: std::pin::Pin<&mut Self>,
-        cx: &mut std::task::Context<'_>,
-    ) -> std::task::Poll<Self::Output> {
-        // SAFETY: We do not move here, just get a reference to the inner value. There is no other data.
-        match unsafe { self.map_unchecked_mut(|x| &mut x.0) }.as_pin_mut() {
-            Some(f) => f.poll(cx).map(Some),
-            None => std::task::Poll::Ready(None),
+        self: core::pin::Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+    ) -> core::task::Poll<Self::Output> {
+        // SAFETY: We do not move anything here, we essentially do as_pin_mut on the inner value.
+        let this = unsafe { self.get_unchecked_mut() };
+        let poll = match &mut this.inner {
+            Some(f) => unsafe { Pin::new_unchecked(f) }.poll(cx).map(Some),
+            None => core::task::Poll::Ready(None),
+        };
+        if poll.is_ready() {
+            this.done = true;
         }
+        poll
+    }
+}
+/// Behind the `futures-core` feature: once `poll` has yielded `Some`/`None`, further polls are
+/// not expected, matching how other combinators track a consumed state.
+#[cfg(feature = "futures-core")]
+impl<F: Future> FusedFuture for TransposedOption<F> {
+    fn is_terminated(&self) -> bool {
+        self.done
     }
 }
-impl<F: IntoFuture, T: Unpin> TransposeFuture for Result<F, T> {
+impl<F: IntoFuture, T> TransposeFuture for Result<F, T> {
     type Output = TransposedResult<F::IntoFuture, T>;
     /// Transpose an Result<impl Future<Output = T>, E> to an impl Future<Output = Result<T, E>>
     ///
@@ -56,27 +80,554 @@ impl<F: IntoFuture, T: Unpin> TransposeFuture for Result<F, T> {
     /// # }
     /// ```
     fn transpose(self) -> Self::Output {
-        TransposedResult(self.map(IntoFuture::into_future).map_err(Some))
+        TransposedResult {
+            inner: self.map(IntoFuture::into_future).map_err(Some),
+            done: false,
+        }
     }
 }
-pub struct TransposedResult<F, T>(Result<F, Option<T>>);
-impl<F: Future, T: Unpin> Future for TransposedResult<F, T> {
+pub struct TransposedResult<F, T> {
+    inner: Result<F, Option<T>>,
+    done: bool,
+}
+impl<F: Future, T> Future for TransposedResult<F, T> {
     type Output = Result<F::Output, T>;
     fn poll(
-        self: std::pin::Pin<&mut Self>,
-        cx: &mut std::task::Context<'_>,
-    ) -> std::task::Poll<Self::Output> {
+        self: core::pin::Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+    ) -> core::task::Poll<Self::Output> {
         // SAFETY: We do not move anything here, we essentially do as_pin_mut on the inner value.
+        let this = unsafe { self.get_unchecked_mut() };
+        let poll = match &mut this.inner {
+            Ok(f) => unsafe { Pin::new_unchecked(f) }.poll(cx).map(Ok),
+            Err(e) => core::task::Poll::Ready(Err(e.take().unwrap())),
+        };
+        if poll.is_ready() {
+            this.done = true;
+        }
+        poll
+    }
+}
+/// Behind the `futures-core` feature: once `poll` has yielded `Ok`/`Err`, further polls are not
+/// expected, matching how other combinators track a consumed state.
+#[cfg(feature = "futures-core")]
+impl<F: Future, T> FusedFuture for TransposedResult<F, T> {
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+/// A single slot of a transposed array: either a still-pending future, its completed output, or
+/// (once moved out into the finished array / dropped by a short-circuit) empty.
+enum ArraySlot<F, T> {
+    Pending(F),
+    Done(T),
+    Taken,
+}
+
+impl<Fut: IntoFuture, E, const N: usize> TransposeFuture for [Result<Fut, E>; N] {
+    type Output = TransposedResultArray<Fut::IntoFuture, E, N>;
+    /// Transpose an [Result<impl Future<Output = T>, E>; N] to an impl Future<Output = Result<[T; N], E>>
+    ///
+    /// ```
+    /// # use transpose_future::TransposeFuture;
+    /// # async fn m() {
+    /// let x: Result<[i32; 2], ()> =
+    ///     [Ok(core::future::ready(1)), Ok(core::future::ready(2))].transpose().await;
+    /// # }
+    /// ```
+    fn transpose(self) -> Self::Output {
+        let mut err = None;
+        let mut results = self.into_iter();
+        let slots = core::array::from_fn(|_| match results.next().unwrap() {
+            Ok(f) if err.is_none() => ArraySlot::Pending(f.into_future()),
+            Ok(_) => ArraySlot::Taken,
+            Err(e) => {
+                err.get_or_insert(e);
+                ArraySlot::Taken
+            }
+        });
+        TransposedResultArray {
+            slots,
+            err,
+            done: false,
+        }
+    }
+}
+pub struct TransposedResultArray<F: Future, E, const N: usize> {
+    slots: [ArraySlot<F, F::Output>; N],
+    err: Option<E>,
+    done: bool,
+}
+impl<F: Future, E, const N: usize> Future for TransposedResultArray<F, E, N> {
+    type Output = Result<[F::Output; N], E>;
+    fn poll(
+        self: core::pin::Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+    ) -> core::task::Poll<Self::Output> {
+        // SAFETY: We do not move anything here, we essentially do as_pin_mut on each slot's future.
+        let this = unsafe { self.get_unchecked_mut() };
+        if let Some(e) = this.err.take() {
+            // One of the inputs was already an Err: drop the remaining pending futures and
+            // short-circuit without polling anything.
+            for slot in &mut this.slots {
+                *slot = ArraySlot::Taken;
+            }
+            this.done = true;
+            return core::task::Poll::Ready(Err(e));
+        }
+        let mut all_done = true;
+        for slot in &mut this.slots {
+            if let ArraySlot::Pending(f) = slot {
+                match unsafe { Pin::new_unchecked(f) }.poll(cx) {
+                    core::task::Poll::Ready(v) => *slot = ArraySlot::Done(v),
+                    core::task::Poll::Pending => all_done = false,
+                }
+            }
+        }
+        if !all_done {
+            return core::task::Poll::Pending;
+        }
+        this.done = true;
+        let mut slots = this.slots.iter_mut();
+        core::task::Poll::Ready(Ok(core::array::from_fn(|_| {
+            match core::mem::replace(slots.next().unwrap(), ArraySlot::Taken) {
+                ArraySlot::Done(v) => v,
+                _ => unreachable!("all slots were checked to be done"),
+            }
+        })))
+    }
+}
+/// Behind the `futures-core` feature: once `poll` has yielded `Ok`/`Err`, further polls are not
+/// expected, matching how other combinators track a consumed state.
+#[cfg(feature = "futures-core")]
+impl<F: Future, E, const N: usize> FusedFuture for TransposedResultArray<F, E, N> {
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+impl<Fut: IntoFuture, const N: usize> TransposeFuture for [Option<Fut>; N] {
+    type Output = TransposedOptionArray<Fut::IntoFuture, N>;
+    /// Transpose an [Option<impl Future<Output = T>>; N] to an impl Future<Output = Option<[T; N]>>
+    ///
+    /// ```
+    /// # use transpose_future::TransposeFuture;
+    /// # async fn m() {
+    /// let x: Option<[i32; 2]> =
+    ///     [Some(core::future::ready(1)), Some(core::future::ready(2))].transpose().await;
+    /// # }
+    /// ```
+    fn transpose(self) -> Self::Output {
+        let mut none = false;
+        let mut options = self.into_iter();
+        let slots = core::array::from_fn(|_| match options.next().unwrap() {
+            Some(f) if !none => ArraySlot::Pending(f.into_future()),
+            Some(_) => ArraySlot::Taken,
+            None => {
+                none = true;
+                ArraySlot::Taken
+            }
+        });
+        TransposedOptionArray {
+            slots,
+            none,
+            done: false,
+        }
+    }
+}
+pub struct TransposedOptionArray<F: Future, const N: usize> {
+    slots: [ArraySlot<F, F::Output>; N],
+    none: bool,
+    done: bool,
+}
+impl<F: Future, const N: usize> Future for TransposedOptionArray<F, N> {
+    type Output = Option<[F::Output; N]>;
+    fn poll(
+        self: core::pin::Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+    ) -> core::task::Poll<Self::Output> {
+        // SAFETY: We do not move anything here, we essentially do as_pin_mut on each slot's future.
+        let this = unsafe { self.get_unchecked_mut() };
+        if this.none {
+            // One of the inputs was already None: drop the remaining pending futures and
+            // short-circuit without polling anything.
+            for slot in &mut this.slots {
+                *slot = ArraySlot::Taken;
+            }
+            this.done = true;
+            return core::task::Poll::Ready(None);
+        }
+        let mut all_done = true;
+        for slot in &mut this.slots {
+            if let ArraySlot::Pending(f) = slot {
+                match unsafe { Pin::new_unchecked(f) }.poll(cx) {
+                    core::task::Poll::Ready(v) => *slot = ArraySlot::Done(v),
+                    core::task::Poll::Pending => all_done = false,
+                }
+            }
+        }
+        if !all_done {
+            return core::task::Poll::Pending;
+        }
+        this.done = true;
+        let mut slots = this.slots.iter_mut();
+        core::task::Poll::Ready(Some(core::array::from_fn(|_| {
+            match core::mem::replace(slots.next().unwrap(), ArraySlot::Taken) {
+                ArraySlot::Done(v) => v,
+                _ => unreachable!("all slots were checked to be done"),
+            }
+        })))
+    }
+}
+/// Behind the `futures-core` feature: once `poll` has yielded `Some`/`None`, further polls are
+/// not expected, matching how other combinators track a consumed state.
+#[cfg(feature = "futures-core")]
+impl<F: Future, const N: usize> FusedFuture for TransposedOptionArray<F, N> {
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+#[cfg(feature = "futures-util")]
+impl<A: IntoFuture, B: IntoFuture> TransposeFuture for Either<A, B> {
+    type Output = TransposedEither<A::IntoFuture, B::IntoFuture>;
+    /// Transpose an Either<impl Future<Output = T>, impl Future<Output = U>> to an
+    /// impl Future<Output = Either<T, U>>
+    ///
+    /// ```
+    /// # use transpose_future::TransposeFuture;
+    /// # use futures_util::future::Either;
+    /// # async fn m() {
+    /// let input: Either<_, core::future::Ready<i32>> = Either::Left(core::future::ready(1));
+    /// let x: Either<i32, i32> = input.transpose().await;
+    /// # }
+    /// ```
+    fn transpose(self) -> Self::Output {
+        TransposedEither(match self {
+            Either::Left(a) => Either::Left(a.into_future()),
+            Either::Right(b) => Either::Right(b.into_future()),
+        })
+    }
+}
+#[cfg(feature = "futures-util")]
+pub struct TransposedEither<A, B>(Either<A, B>);
+#[cfg(feature = "futures-util")]
+impl<A: Future, B: Future> Future for TransposedEither<A, B> {
+    type Output = Either<A::Output, B::Output>;
+    fn poll(
+        self: core::pin::Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+    ) -> core::task::Poll<Self::Output> {
+        // SAFETY: We do not move anything here, we essentially do as_pin_mut on the active variant.
         let mapped = unsafe {
             let x = self.get_unchecked_mut();
             match &mut x.0 {
-                Ok(f) => Ok(Pin::new_unchecked(f)),
-                Err(e) => Err(Pin::new_unchecked(e)),
+                Either::Left(a) => Either::Left(Pin::new_unchecked(a)),
+                Either::Right(b) => Either::Right(Pin::new_unchecked(b)),
             }
         };
         match mapped {
-            Ok(f) => f.poll(cx).map(Ok),
-            Err(e) => std::task::Poll::Ready(Err(e.get_mut().take().unwrap())),
+            Either::Left(a) => a.poll(cx).map(Either::Left),
+            Either::Right(b) => b.poll(cx).map(Either::Right),
+        }
+    }
+}
+
+/// Like [`TransposeFuture`], but for a future wrapped in two layers of `Option`/`Result`.
+///
+/// This is a separate trait (rather than more blanket `TransposeFuture` impls over
+/// `Result<Option<F>, E>` / `Option<Result<F, E>>`) for two reasons: those shapes would overlap
+/// with the existing generic `Option<F>`/`Result<F, T>` impls under coherence, and `Result<Option<T>,
+/// E>`/`Option<Result<T, E>>` already have an inherent `std` `transpose` with unrelated semantics
+/// that would shadow a same-named trait method anyway.
+pub trait TransposeNestedFuture {
+    type Output: Future;
+    fn transpose_nested(self) -> Self::Output;
+}
+impl<F: IntoFuture, E> TransposeNestedFuture for Result<Option<F>, E> {
+    type Output = TransposedResult<TransposedOption<F::IntoFuture>, E>;
+    /// Transpose a Result<Option<impl Future<Output = T>>, E> to an
+    /// impl Future<Output = Result<Option<T>, E>>
+    ///
+    /// ```
+    /// # use transpose_future::{TransposeFuture, TransposeNestedFuture};
+    /// # async fn m() {
+    /// let x: Result<Option<i32>, ()> = Ok(Some(async { 5 })).transpose_nested().await;
+    /// # }
+    /// ```
+    fn transpose_nested(self) -> Self::Output {
+        self.map(TransposeFuture::transpose).transpose()
+    }
+}
+impl<F: IntoFuture, E> TransposeNestedFuture for Option<Result<F, E>> {
+    type Output = TransposedOption<TransposedResult<F::IntoFuture, E>>;
+    /// Transpose an Option<Result<impl Future<Output = T>, E>> to an
+    /// impl Future<Output = Option<Result<T, E>>>
+    ///
+    /// ```
+    /// # use transpose_future::{TransposeFuture, TransposeNestedFuture};
+    /// # async fn m() {
+    /// let x: Option<Result<i32, ()>> = Some(Ok(async { 5 })).transpose_nested().await;
+    /// # }
+    /// ```
+    fn transpose_nested(self) -> Self::Output {
+        self.map(TransposeFuture::transpose).transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::Cell;
+    use core::future::ready;
+
+    /// A future that panics if it is ever polled, used to prove a slot was skipped entirely.
+    struct PanicsIfPolled;
+    impl Future for PanicsIfPolled {
+        type Output = i32;
+        fn poll(self: Pin<&mut Self>, _cx: &mut core::task::Context<'_>) -> core::task::Poll<i32> {
+            panic!("this future must never be polled");
+        }
+    }
+
+    /// A pending future that records how many times its drop runs, to prove short-circuited
+    /// slots are actually dropped rather than leaked.
+    struct DropCounts<'a>(&'a Cell<i32>);
+    impl Future for DropCounts<'_> {
+        type Output = i32;
+        fn poll(self: Pin<&mut Self>, _cx: &mut core::task::Context<'_>) -> core::task::Poll<i32> {
+            core::task::Poll::Pending
+        }
+    }
+    impl Drop for DropCounts<'_> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    /// A future that asserts it is polled at the same address every time, to prove it is never
+    /// moved out from under its `Pin` between polls.
+    struct PanicsIfMoved {
+        first_poll_addr: Cell<Option<usize>>,
+        polls_before_ready: Cell<u32>,
+    }
+    impl Future for PanicsIfMoved {
+        type Output = i32;
+        fn poll(self: Pin<&mut Self>, _cx: &mut core::task::Context<'_>) -> core::task::Poll<i32> {
+            let addr = &*self as *const Self as usize;
+            match self.first_poll_addr.get() {
+                None => self.first_poll_addr.set(Some(addr)),
+                Some(prev) => assert_eq!(prev, addr, "future was moved between polls"),
+            }
+            let remaining = self.polls_before_ready.get();
+            if remaining == 0 {
+                core::task::Poll::Ready(7)
+            } else {
+                self.polls_before_ready.set(remaining - 1);
+                core::task::Poll::Pending
+            }
+        }
+    }
+
+    fn noop_waker() -> core::task::Waker {
+        fn clone(_: *const ()) -> core::task::RawWaker {
+            raw_waker()
+        }
+        fn noop(_: *const ()) {}
+        fn raw_waker() -> core::task::RawWaker {
+            static VTABLE: core::task::RawWakerVTable =
+                core::task::RawWakerVTable::new(clone, noop, noop, noop);
+            core::task::RawWaker::new(core::ptr::null(), &VTABLE)
+        }
+        unsafe { core::task::Waker::from_raw(raw_waker()) }
+    }
+
+    fn poll_once<F: Future>(fut: core::pin::Pin<&mut F>) -> core::task::Poll<F::Output> {
+        let waker = noop_waker();
+        let mut cx = core::task::Context::from_waker(&waker);
+        fut.poll(&mut cx)
+    }
+
+    #[test]
+    fn transpose_option_some() {
+        let fut = Some(ready(5)).transpose();
+        let mut fut = core::pin::pin!(fut);
+        assert_eq!(poll_once(fut.as_mut()), core::task::Poll::Ready(Some(5)));
+    }
+
+    #[test]
+    fn transpose_option_none() {
+        let fut: Option<core::future::Ready<i32>> = None;
+        let fut = fut.transpose();
+        let mut fut = core::pin::pin!(fut);
+        assert_eq!(poll_once(fut.as_mut()), core::task::Poll::Ready(None));
+    }
+
+    #[test]
+    fn transpose_result_ok() {
+        let fut: Result<_, ()> = Ok(ready(5));
+        let fut = fut.transpose();
+        let mut fut = core::pin::pin!(fut);
+        assert_eq!(poll_once(fut.as_mut()), core::task::Poll::Ready(Ok(5)));
+    }
+
+    #[test]
+    fn transpose_result_err() {
+        let fut: Result<core::future::Ready<i32>, &str> = Err("boom");
+        let fut = fut.transpose();
+        let mut fut = core::pin::pin!(fut);
+        assert_eq!(poll_once(fut.as_mut()), core::task::Poll::Ready(Err("boom")));
+    }
+
+    #[cfg(feature = "futures-core")]
+    #[test]
+    fn transpose_option_is_terminated_after_ready() {
+        let fut = Some(ready(5)).transpose();
+        let mut fut = core::pin::pin!(fut);
+        assert!(!fut.is_terminated());
+        let _ = poll_once(fut.as_mut());
+        assert!(fut.is_terminated());
+    }
+
+    #[cfg(feature = "futures-core")]
+    #[test]
+    fn transpose_result_is_terminated_after_ready() {
+        let fut: Result<core::future::Ready<i32>, &str> = Err("boom");
+        let fut = fut.transpose();
+        let mut fut = core::pin::pin!(fut);
+        assert!(!fut.is_terminated());
+        let _ = poll_once(fut.as_mut());
+        assert!(fut.is_terminated());
+    }
+
+    #[test]
+    fn transpose_result_array_ok() {
+        let fut = [Ok::<_, ()>(ready(1)), Ok(ready(2))].transpose();
+        let mut fut = core::pin::pin!(fut);
+        assert_eq!(poll_once(fut.as_mut()), core::task::Poll::Ready(Ok([1, 2])));
+    }
+
+    #[test]
+    fn transpose_result_array_short_circuits_without_polling_other_slots() {
+        // The first slot would panic if polled; it never should be, since the second slot is
+        // already a known Err by the time poll() runs.
+        let fut = [Ok(PanicsIfPolled), Err("boom")].transpose();
+        let mut fut = core::pin::pin!(fut);
+        assert_eq!(poll_once(fut.as_mut()), core::task::Poll::Ready(Err("boom")));
+    }
+
+    #[test]
+    fn transpose_result_array_drops_pending_slots_on_short_circuit() {
+        let counter = Cell::new(0);
+        let fut = [Ok(DropCounts(&counter)), Err("boom")].transpose();
+        let mut fut = core::pin::pin!(fut);
+        assert_eq!(poll_once(fut.as_mut()), core::task::Poll::Ready(Err("boom")));
+        assert_eq!(counter.get(), 1);
+    }
+
+    #[test]
+    fn transpose_result_array_never_moves_a_pending_slot() {
+        let fut: [Result<_, ()>; 2] = [
+            Ok(PanicsIfMoved {
+                first_poll_addr: Cell::new(None),
+                polls_before_ready: Cell::new(1),
+            }),
+            Ok(PanicsIfMoved {
+                first_poll_addr: Cell::new(None),
+                polls_before_ready: Cell::new(0),
+            }),
+        ];
+        let fut = fut.transpose();
+        let mut fut = core::pin::pin!(fut);
+        assert_eq!(poll_once(fut.as_mut()), core::task::Poll::Pending);
+        assert_eq!(poll_once(fut.as_mut()), core::task::Poll::Ready(Ok([7, 7])));
+    }
+
+    #[cfg(feature = "futures-core")]
+    #[test]
+    fn transpose_result_array_is_terminated_after_ready() {
+        let fut = [Ok(PanicsIfPolled), Err("boom")].transpose();
+        let mut fut = core::pin::pin!(fut);
+        assert!(!fut.is_terminated());
+        let _ = poll_once(fut.as_mut());
+        assert!(fut.is_terminated());
+    }
+
+    #[test]
+    fn transpose_option_array_some() {
+        let fut = TransposeFuture::transpose([Some(ready(1)), Some(ready(2))]);
+        let mut fut = core::pin::pin!(fut);
+        assert_eq!(poll_once(fut.as_mut()), core::task::Poll::Ready(Some([1, 2])));
+    }
+
+    #[test]
+    fn transpose_option_array_short_circuits_without_polling_other_slots() {
+        let fut = TransposeFuture::transpose([Some(PanicsIfPolled), None]);
+        let mut fut = core::pin::pin!(fut);
+        assert_eq!(poll_once(fut.as_mut()), core::task::Poll::Ready(None));
+    }
+
+    #[test]
+    fn transpose_option_array_drops_pending_slots_on_short_circuit() {
+        let counter = Cell::new(0);
+        let fut = TransposeFuture::transpose([Some(DropCounts(&counter)), None]);
+        let mut fut = core::pin::pin!(fut);
+        assert_eq!(poll_once(fut.as_mut()), core::task::Poll::Ready(None));
+        assert_eq!(counter.get(), 1);
+    }
+
+    #[test]
+    fn transpose_option_array_never_moves_a_pending_slot() {
+        let fut = [
+            Some(PanicsIfMoved {
+                first_poll_addr: Cell::new(None),
+                polls_before_ready: Cell::new(1),
+            }),
+            Some(PanicsIfMoved {
+                first_poll_addr: Cell::new(None),
+                polls_before_ready: Cell::new(0),
+            }),
+        ];
+        let fut = TransposeFuture::transpose(fut);
+        let mut fut = core::pin::pin!(fut);
+        assert_eq!(poll_once(fut.as_mut()), core::task::Poll::Pending);
+        assert_eq!(poll_once(fut.as_mut()), core::task::Poll::Ready(Some([7, 7])));
+    }
+
+    #[cfg(feature = "futures-core")]
+    #[test]
+    fn transpose_option_array_is_terminated_after_ready() {
+        let fut = TransposeFuture::transpose([Some(PanicsIfPolled), None]);
+        let mut fut = core::pin::pin!(fut);
+        assert!(!fut.is_terminated());
+        let _ = poll_once(fut.as_mut());
+        assert!(fut.is_terminated());
+    }
+
+    #[cfg(feature = "futures-util")]
+    #[test]
+    fn transpose_either_left() {
+        use futures_util::future::Either;
+        let input: Either<_, core::future::Ready<i32>> = Either::Left(ready(1));
+        let fut = input.transpose();
+        let mut fut = core::pin::pin!(fut);
+        match poll_once(fut.as_mut()) {
+            core::task::Poll::Ready(Either::Left(v)) => assert_eq!(v, 1),
+            other => panic!("expected Ready(Either::Left(1)), got {other:?}"),
+        }
+    }
+
+    #[cfg(feature = "futures-util")]
+    #[test]
+    fn transpose_either_right() {
+        use futures_util::future::Either;
+        let input: Either<core::future::Ready<i32>, _> = Either::Right(ready(2));
+        let fut = input.transpose();
+        let mut fut = core::pin::pin!(fut);
+        match poll_once(fut.as_mut()) {
+            core::task::Poll::Ready(Either::Right(v)) => assert_eq!(v, 2),
+            other => panic!("expected Ready(Either::Right(2)), got {other:?}"),
         }
     }
 }